@@ -1,20 +1,27 @@
-let PI: f64 = 3.14159;
+const PI: f64 = 3.14159;
 
 struct Shape {
-    pub type: String,
+    pub r#type: String,
 }
 
 impl Shape {
-    pub fn new(type: String) -> Self {
+    pub fn new(r#type: String) -> Self {
         Self {
-            type: type,
+            r#type: r#type,
         }
 }
     pub fn getType(&self) -> String {
-        self.type
+        self.r#type.clone()
 }
 }
 
+// Circle and Rectangle both declare an `area` method with the same
+// signature, so it is routed onto a shared trait instead of being
+// duplicated as independent inherent methods.
+pub trait Area {
+    fn area(&self) -> f64;
+}
+
 struct Circle {
     pub radius: f64,
 }
@@ -24,12 +31,15 @@ impl Circle {
         Self {
             radius: r,
         }
-}
-    pub fn area(&self) -> f64 {
-        PI * self.radius * self.radius
 }
     pub fn circumference(&self) -> f64 {
-        2 * PI * self.radius
+        2.0 * PI * self.radius
+}
+}
+
+impl Area for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
 }
 }
 
@@ -44,28 +54,33 @@ impl Rectangle {
             width: w,
             height: h,
         }
-}
-    pub fn area(&self) -> f64 {
-        self.width * self.height
 }
     pub fn perimeter(&self) -> f64 {
-        2 * self.width + self.height
+        2.0 * self.width + self.height
 }
 }
 
-let circle = Circle::new(5);
+impl Area for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+}
+}
+
+fn main() {
+    let circle = Circle::new(5.0);
 
-println!("{:?} {:?}", "Circle - Radius:", circle.radius);
+    println!("{:?} {:?}", "Circle - Radius:", circle.radius);
 
-println!("{:?} {:?}", "Circle - Area:", circle.area());
+    println!("{:?} {:?}", "Circle - Area:", circle.area());
 
-println!("{:?} {:?}", "Circle - Circumference:", circle.circumference());
+    println!("{:?} {:?}", "Circle - Circumference:", circle.circumference());
 
-let rectangle = Rectangle::new(4, 6);
+    let rectangle = Rectangle::new(4.0, 6.0);
 
-println!("{:?} {:?} {:?} {:?}", "Rectangle - Width:", rectangle.width, "Height:", rectangle.height);
+    println!("{:?} {:?} {:?} {:?}", "Rectangle - Width:", rectangle.width, "Height:", rectangle.height);
 
-println!("{:?} {:?}", "Rectangle - Area:", rectangle.area());
+    println!("{:?} {:?}", "Rectangle - Area:", rectangle.area());
 
-println!("{:?} {:?}", "Rectangle - Perimeter:", rectangle.perimeter());
+    println!("{:?} {:?}", "Rectangle - Perimeter:", rectangle.perimeter());
+}
 
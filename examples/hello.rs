@@ -1,5 +1,5 @@
 fn main() {
-    let message: String = "Hello, ScriptRust!";
+    let message: String = "Hello, ScriptRust!".to_string();
 
     fn greet(name: String) -> String {
         format!("Hello, {}!", name)
@@ -7,8 +7,8 @@ fn main() {
 
     println!("{:?}", message);
 
-    println!("{:?}", greet("World"));
+    println!("{:?}", greet("World".to_string()));
 
-    println!("{:?}", greet("Developer"));
+    println!("{:?}", greet("Developer".to_string()));
 
 }
@@ -1,4 +1,5 @@
 fn main() {
+    #[derive(Clone)]
     struct Resource {
         pub id: String,
         pub refCount: f64,
@@ -9,27 +10,28 @@ fn main() {
             println!("{:?} {:?}", "Resource created:", id);
             Self {
                 id: id,
+                refCount: 0.0,
             }
 }
-        pub fn borrow(&self) -> () {
-            self.refCount = self.refCount + 1;
+        pub fn borrow(&mut self) -> () {
+            self.refCount = self.refCount + 1.0;
             println!("{:?} {:?} {:?} {:?}", "Resource borrowed:", self.id, "- refs:", self.refCount);
 }
-        pub fn release(&self) -> () {
-            self.refCount = self.refCount - 1;
+        pub fn release(&mut self) -> () {
+            self.refCount = self.refCount - 1.0;
             println!("{:?} {:?} {:?} {:?}", "Resource released:", self.id, "- refs:", self.refCount);
-            if self.refCount == 0 {
+            if self.refCount == 0.0 {
                 println!("{:?} {:?}", "Resource freed:", self.id);
 }
 }
         pub fn getId(&self) -> String {
-            self.id
+            self.id.clone()
 }
     }
 
-    let resource1 = Resource::new("DB-Connection-1");
+    let mut resource1 = Resource::new("DB-Connection-1".to_string());
 
-    let borrowed = resource1;
+    let mut borrowed = resource1.clone();
 
     borrowed.borrow();
 